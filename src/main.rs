@@ -1,10 +1,10 @@
 use anyhow::{Context, Result};
 use clap::{App, Arg};
-use lines_are_rusty::{LayerColor, LinesData};
-use std::fs::{metadata, File};
+use lines_are_rusty::{FileFormat, LayerColor, LinesData, RenderOptions, SvgVersion};
+use std::fs::{self, metadata, File};
 use std::io::Read;
 use std::io::{self, BufWriter, Write};
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::process::exit;
 
 fn main() -> Result<()> {
@@ -43,7 +43,7 @@ fn main() -> Result<()> {
                 .long("to")
                 .takes_value(true)
                 .help("Output type. If present, overrides the type determined by the output file extension. Defaults to svg.")
-                .possible_values(&["svg", "pdf"])
+                .possible_values(&["svg", "pdf", "ps", "png"])
         )
         .arg(
             Arg::with_name("template")
@@ -64,6 +64,27 @@ fn main() -> Result<()> {
             .long("debug-dump")
             .help("When rendering SVG, write debug information about lines and points into the SVG as tooltips")
         )
+        .arg(
+            Arg::with_name("page")
+                .long("page")
+                .takes_value(true)
+                .help("Render only the given page (1-indexed). If omitted, all pages are rendered.")
+        )
+        .arg(
+            Arg::with_name("dpi")
+                .long("dpi")
+                .takes_value(true)
+                .help("Pixel density to rasterize PNG output at")
+                .default_value("226.0")
+        )
+        .arg(
+            Arg::with_name("svg-version")
+                .long("svg-version")
+                .takes_value(true)
+                .help("Declared version/baseProfile of generated SVG output")
+                .possible_values(&["1.1", "1.2"])
+                .default_value("1.1")
+        )
         .get_matches();
     let output_filename = matches.value_of("output");
     let output_type_string = matches.value_of("output-type").or({
@@ -72,15 +93,12 @@ fn main() -> Result<()> {
             .and_then(|extension| extension.to_str())
     });
     let output_type = match output_type_string {
-        Some(output_type_string) => match output_type_string.to_lowercase().as_ref() {
-            "svg" => OutputType::Svg,
-            "pdf" => OutputType::Pdf,
-            _ => {
+        Some(output_type_string) => FileFormat::from_extension(output_type_string)
+            .unwrap_or_else(|| {
                 eprintln!("Unsupported output file extension {}", output_type_string);
                 exit(1);
-            }
-        },
-        None => OutputType::Svg,
+            }),
+        None => FileFormat::Svg,
     };
 
     let auto_crop = matches.is_present("auto-crop");
@@ -120,40 +138,74 @@ fn main() -> Result<()> {
 
     let template: Option<&str> = matches.value_of("template");
 
+    let page_selector: Option<usize> = matches
+        .value_of("page")
+        .map(|page| page.parse().expect("page not a valid page number"));
+
     let debug_dump = matches.is_present("debug-dump");
-    if debug_dump && (output_type != OutputType::Svg) {
-        eprintln!("Warning: debug-dump only has an effect when writing SVG output");
+    if debug_dump && !matches!(output_type, FileFormat::Svg | FileFormat::Ps) {
+        eprintln!("Warning: debug-dump only has an effect when writing SVG or PS output");
     }
 
-    let options = Options {
-        output_type,
-        output_filename,
-        layer_colors,
+    let dpi: f32 = matches
+        .value_of("dpi")
+        .expect("Failed to read dpi")
+        .parse()
+        .expect("dpi not a valid f32");
+
+    if matches.occurrences_of("svg-version") > 0 && output_type != FileFormat::Svg {
+        eprintln!("Warning: svg-version only has an effect when writing SVG output");
+    }
+    let svg_version = SvgVersion::from_str(
+        matches
+            .value_of("svg-version")
+            .expect("Failed to read svg-version"),
+    )
+    .expect("svg-version not a valid version");
+
+    let render_options = RenderOptions {
         auto_crop,
+        layer_colors,
         distance_threshold,
         template,
         debug_dump,
+        svg_version,
+        dpi,
     };
 
-    let mut output = BufWriter::new(match output_filename {
-        Some(output_filename) => Box::new(
-            File::create(output_filename).context(format!("Can't create {}", output_filename))?,
-        ),
-        None => Box::new(io::stdout()) as Box<dyn Write>,
-    });
-
     match matches.value_of("file") {
-        None => process_single_file(&mut io::stdin(), &mut output, options)?,
+        None => {
+            process_single_file(
+                &mut io::stdin(),
+                output_type,
+                output_filename,
+                page_selector,
+                render_options,
+            )?;
+        }
         Some(filename) => {
             let metadata =
                 metadata(filename).context(format!("Can't access input file {}", filename))?;
             if metadata.is_dir() {
-                println!("Can't process directories yet");
-                exit(1);
+                let output_dir = output_filename
+                    .context("Output directory (-o) needed when converting a directory")?;
+                process_directory(
+                    Path::new(filename),
+                    Path::new(output_dir),
+                    output_type,
+                    page_selector,
+                    &render_options,
+                )?;
             } else {
                 let mut input =
                     File::open(filename).context(format!("Can't open input file {}", filename))?;
-                process_single_file(&mut input, &mut output, options)?;
+                process_single_file(
+                    &mut input,
+                    output_type,
+                    output_filename,
+                    page_selector,
+                    render_options,
+                )?;
             }
         }
     };
@@ -163,48 +215,175 @@ fn main() -> Result<()> {
     Ok(())
 }
 
+fn open_output(output_filename: Option<&str>) -> Result<BufWriter<Box<dyn Write>>> {
+    Ok(BufWriter::new(match output_filename {
+        Some(output_filename) => Box::new(
+            File::create(output_filename).context(format!("Can't create {}", output_filename))?,
+        ),
+        None => Box::new(io::stdout()) as Box<dyn Write>,
+    }))
+}
+
+/// Recursively converts every `.rm`/`.lines` file under `input_dir`, mapping
+/// each one to an output file with the same relative path (and the
+/// `output_type`'s extension) rooted at `output_dir`. A multi-page input in a
+/// format other than PDF becomes one file per page instead, named as
+/// `page_output_filename` derives them from that path.
+fn process_directory(
+    input_dir: &Path,
+    output_dir: &Path,
+    output_type: FileFormat,
+    page_selector: Option<usize>,
+    render_options: &RenderOptions,
+) -> Result<()> {
+    let mut input_files = Vec::new();
+    collect_input_files(input_dir, &mut input_files)?;
+
+    for input_path in input_files {
+        let relative_path = input_path
+            .strip_prefix(input_dir)
+            .expect("input files are always found inside input_dir");
+        let output_path = output_dir
+            .join(relative_path)
+            .with_extension(extension_for(output_type));
+
+        if let Some(parent) = output_path.parent() {
+            fs::create_dir_all(parent)
+                .context(format!("Can't create directory {}", parent.display()))?;
+        }
+
+        let output_path_string = output_path.to_string_lossy().into_owned();
+        let mut input = File::open(&input_path)
+            .context(format!("Can't open input file {}", input_path.display()))?;
+
+        process_single_file(
+            &mut input,
+            output_type,
+            Some(&output_path_string),
+            page_selector,
+            render_options.clone(),
+        )
+        .context(format!("failed to process {}", input_path.display()))?;
+    }
+
+    Ok(())
+}
+
+/// Recursively collects `.rm`/`.lines` files under `dir` into `files`.
+fn collect_input_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<()> {
+    for entry in fs::read_dir(dir).context(format!("Can't read directory {}", dir.display()))? {
+        let path = entry
+            .context(format!("Can't read entry in directory {}", dir.display()))?
+            .path();
+        if path.is_dir() {
+            collect_input_files(&path, files)?;
+        } else if path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.eq_ignore_ascii_case("rm") || extension.eq_ignore_ascii_case("lines"))
+            .unwrap_or(false)
+        {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
+
+fn extension_for(output_type: FileFormat) -> &'static str {
+    match output_type {
+        FileFormat::Svg => "svg",
+        FileFormat::Pdf => "pdf",
+        FileFormat::Ps => "ps",
+        FileFormat::Png => "png",
+    }
+}
+
+/// Converts a single `.rm`/`.lines` file read from `input`, writing the
+/// result to `output_filename` (or stdout if omitted).
+///
+/// `output_filename` is only opened once it's known the per-page-split path
+/// below isn't taken, since that path creates its own files instead.
 fn process_single_file(
     mut input: &mut dyn Read,
-    output: &mut dyn Write,
-    opts: Options,
+    output_type: FileFormat,
+    output_filename: Option<&str>,
+    page_selector: Option<usize>,
+    render_options: RenderOptions,
 ) -> Result<()> {
     let lines_data = LinesData::parse(&mut input).context("Failed to parse lines data")?;
 
-    match opts.output_type {
-        OutputType::Svg => lines_are_rusty::render_svg(
-            output,
-            &lines_data.pages[0],
-            opts.auto_crop,
-            &opts.layer_colors,
-            opts.distance_threshold,
-            opts.template,
-            opts.debug_dump,
-        )
-        .context("failed to write SVG")?,
-        OutputType::Pdf => {
-            // Alas, the pdf-canvas crate insists on writing to a File instead of a Write
-            let pdf_filename = opts
-                .output_filename
-                .context("Output file needed for PDF output")?;
-            lines_are_rusty::render_pdf(pdf_filename, &lines_data.pages)
-                .context("failed to write pdf")?
+    let pages: &[lines_are_rusty::Page] = match page_selector {
+        Some(page) => {
+            let page_index = page
+                .checked_sub(1)
+                .context("page numbers are 1-indexed")?;
+            lines_data
+                .pages
+                .get(page_index..=page_index)
+                .context(format!(
+                    "page {} out of range, file has {} pages",
+                    page,
+                    lines_data.pages.len()
+                ))?
+        }
+        None => &lines_data.pages[..],
+    };
+
+    // Every format but PDF can only hold a single page per document (SVG and
+    // PS are one-page-per-document formats, and PNG is rasterized from a
+    // single page's SVG), so when no single --page was requested we fall back
+    // to one output file per page instead of silently dropping the rest.
+    if output_type != FileFormat::Pdf && page_selector.is_none() && pages.len() > 1 {
+        let output_filename = output_filename
+            .context("Output filename template needed to render multiple pages")?;
+        for (page_index, page) in pages.iter().enumerate() {
+            let page_filename = page_output_filename(output_filename, page_index);
+            let mut page_output = BufWriter::new(
+                File::create(&page_filename).context(format!("Can't create {}", page_filename))?,
+            );
+            lines_are_rusty::export(
+                std::slice::from_ref(page),
+                &mut page_output,
+                output_type,
+                Some(&page_filename),
+                &render_options,
+            )
+            .context(format!("failed to render {}", page_filename))?;
         }
+    } else {
+        let mut output = open_output(output_filename)?;
+        lines_are_rusty::export(pages, &mut output, output_type, output_filename, &render_options)
+            .context("failed to render output")?;
     }
+
     Ok(())
 }
 
-#[derive(Debug, PartialEq)]
-enum OutputType {
-    Svg,
-    Pdf,
+/// Derives a per-page output filename from a template filename, e.g.
+/// `out.svg` becomes `out-0.svg`, `out-1.svg`, ... for each page.
+fn page_output_filename(template: &str, page_index: usize) -> String {
+    let path = Path::new(template);
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("out");
+    let extension = path
+        .extension()
+        .and_then(|s| s.to_str())
+        .unwrap_or("svg");
+    let file_name = format!("{}-{}.{}", stem, page_index, extension);
+    match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => {
+            parent.join(file_name).to_string_lossy().into_owned()
+        }
+        _ => file_name,
+    }
 }
 
-struct Options<'a> {
-    output_type: OutputType,
-    output_filename: Option<&'a str>,
-    layer_colors: Vec<LayerColor>,
-    auto_crop: bool,
-    distance_threshold: f32,
-    template: Option<&'a str>,
-    debug_dump: bool,
+#[test]
+fn test_page_output_filename() {
+    assert_eq!(page_output_filename("out.svg", 0), "out-0.svg");
+    assert_eq!(page_output_filename("out.svg", 1), "out-1.svg");
+    assert_eq!(
+        page_output_filename("some/dir/out.ps", 2),
+        "some/dir/out-2.ps"
+    );
+    assert_eq!(page_output_filename("out", 0), "out-0.svg");
 }
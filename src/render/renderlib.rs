@@ -1,5 +1,160 @@
 use crate::{BrushType, Color, LayerColor, Line, Page, Point};
+use anyhow::{Context, Result};
 use core::f32::{INFINITY, NEG_INFINITY};
+use std::io::Write;
+
+/// Nominal reMarkable page size in points, used as the PostScript page size
+/// when the caller doesn't want the output cropped to the drawn content.
+const REMARKABLE_WIDTH: f32 = 1404.0;
+const REMARKABLE_HEIGHT: f32 = 1872.0;
+
+/// The reMarkable's native pixel density, used as the default `--dpi` for PNG
+/// output so a page renders 1:1 with the device's screen.
+const REMARKABLE_DPI: f32 = 226.0;
+
+/// A vector or raster format `export` can write a page out as.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FileFormat {
+    Svg,
+    Pdf,
+    Ps,
+    Png,
+}
+
+impl FileFormat {
+    /// Looks up a format by its usual file extension, case-insensitively.
+    pub fn from_extension(extension: &str) -> Option<FileFormat> {
+        match extension.to_lowercase().as_ref() {
+            "svg" => Some(FileFormat::Svg),
+            "pdf" => Some(FileFormat::Pdf),
+            "ps" => Some(FileFormat::Ps),
+            "png" => Some(FileFormat::Png),
+            _ => None,
+        }
+    }
+}
+
+/// The declared `version`/`baseProfile` of generated SVG output. Some
+/// downstream consumers (older Inkscape/Illustrator pipelines, e-ink
+/// firmware that only understands SVG Tiny) reject the newer constructs
+/// `V1_2` output may use, so callers can pin it to `V1_1`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SvgVersion {
+    V1_1,
+    V1_2,
+}
+
+impl SvgVersion {
+    /// Looks up a version by the CLI-facing string ("1.1", "1.2").
+    pub fn from_str(value: &str) -> Option<SvgVersion> {
+        match value {
+            "1.1" => Some(SvgVersion::V1_1),
+            "1.2" => Some(SvgVersion::V1_2),
+            _ => None,
+        }
+    }
+}
+
+impl Default for SvgVersion {
+    fn default() -> Self {
+        SvgVersion::V1_1
+    }
+}
+
+/// Rendering knobs shared by every [`FileFormat`], so library consumers get
+/// the same surface the CLI does instead of having to duplicate it per format.
+#[derive(Debug, Clone)]
+pub struct RenderOptions<'a> {
+    pub auto_crop: bool,
+    pub layer_colors: Vec<LayerColor>,
+    pub distance_threshold: f32,
+    pub template: Option<&'a str>,
+    pub debug_dump: bool,
+    /// Declared version/profile of generated SVG output. Has no effect on
+    /// other formats.
+    pub svg_version: SvgVersion,
+    /// Pixel density used to rasterize [`FileFormat::Png`] output.
+    pub dpi: f32,
+}
+
+impl<'a> Default for RenderOptions<'a> {
+    fn default() -> Self {
+        RenderOptions {
+            auto_crop: false,
+            layer_colors: Vec::new(),
+            distance_threshold: 2.0,
+            template: None,
+            debug_dump: false,
+            svg_version: SvgVersion::default(),
+            dpi: REMARKABLE_DPI,
+        }
+    }
+}
+
+/// Renders `pages` as `format` to `writer`, dispatching to the
+/// format-specific renderer. This is the single entry point `main` and other
+/// library consumers should use instead of calling `render_svg`/`render_pdf`/
+/// `render_ps` directly; adding a future format only needs a new match arm
+/// here.
+///
+/// PDF currently can't stream to an arbitrary `Write` (the pdf-canvas crate
+/// insists on a `File`), so `output_filename` is required for `FileFormat::Pdf`.
+///
+/// Every format but PDF can only represent a single page per document, so
+/// callers with multiple pages and a format other than PDF must split them
+/// into one `export` call per page themselves (see `page_output_filename` in
+/// `main`); passing more than one page here for any other format returns an
+/// error.
+pub fn export(
+    pages: &[Page],
+    writer: &mut dyn Write,
+    format: FileFormat,
+    output_filename: Option<&str>,
+    options: &RenderOptions,
+) -> Result<()> {
+    if format != FileFormat::Pdf && pages.len() > 1 {
+        anyhow::bail!(
+            "{:?} output can only hold a single page, but {} pages were given; split multi-page input into one output per page first",
+            format,
+            pages.len()
+        );
+    }
+    match format {
+        FileFormat::Svg => crate::render_svg(
+            writer,
+            &pages[0],
+            options.auto_crop,
+            &options.layer_colors,
+            options.distance_threshold,
+            options.template,
+            options.debug_dump,
+            options.svg_version,
+        ),
+        FileFormat::Ps => render_ps(
+            writer,
+            &pages[0],
+            options.auto_crop,
+            &options.layer_colors,
+            options.distance_threshold,
+            options.template,
+            options.debug_dump,
+        ),
+        FileFormat::Pdf => {
+            let pdf_filename =
+                output_filename.context("Output file needed for PDF output")?;
+            crate::render_pdf(pdf_filename, pages)
+        }
+        FileFormat::Png => render_png(
+            writer,
+            &pages[0],
+            options.auto_crop,
+            &options.layer_colors,
+            options.distance_threshold,
+            options.template,
+            options.dpi,
+        ),
+    }
+}
 
 pub(crate) struct BoundingBox {
     pub min_x: f32,
@@ -97,6 +252,402 @@ pub(crate) fn segment_quads(line: &Line) -> Vec<f32> {
     )
 }
 
+/// Parses a color the same way [`line_to_css_color`] or `--colors` produced
+/// it — hex triplet, `rgb()`/`rgba()`, `hsl()`/`hsla()`, or any CSS3 named
+/// color — into the `setrgbcolor` triple PostScript expects. Alpha
+/// components are accepted but dropped, since PostScript has no transparency
+/// here.
+///
+/// `render_svg`/`render_pdf` hand these strings straight to their output
+/// format and never need to parse them, so this has to cover the same
+/// surface users can reach through `--colors`, not just what the built-in
+/// layer defaults emit.
+fn css_color_to_rgb(css: &str) -> Result<(f32, f32, f32)> {
+    let css = css.trim();
+    if let Some(hex) = css.strip_prefix('#') {
+        if hex.len() == 6 {
+            if let (Ok(r), Ok(g), Ok(b)) = (
+                u8::from_str_radix(&hex[0..2], 16),
+                u8::from_str_radix(&hex[2..4], 16),
+                u8::from_str_radix(&hex[4..6], 16),
+            ) {
+                return Ok((r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0));
+            }
+        }
+    }
+    for prefix in ["rgb(", "rgba("] {
+        if let Some(inner) = css.strip_prefix(prefix).and_then(|s| s.strip_suffix(')')) {
+            let parts: Vec<f32> = inner
+                .split(',')
+                .filter_map(|part| part.trim().parse::<f32>().ok())
+                .collect();
+            if parts.len() >= 3 {
+                return Ok((parts[0] / 255.0, parts[1] / 255.0, parts[2] / 255.0));
+            }
+        }
+    }
+    for prefix in ["hsl(", "hsla("] {
+        if let Some(inner) = css.strip_prefix(prefix).and_then(|s| s.strip_suffix(')')) {
+            let parts: Vec<&str> = inner.split(',').map(|part| part.trim()).collect();
+            if parts.len() >= 3 {
+                if let (Some(h), Some(s), Some(l)) = (
+                    parts[0].parse::<f32>().ok(),
+                    parts[1].strip_suffix('%').and_then(|v| v.parse::<f32>().ok()),
+                    parts[2].strip_suffix('%').and_then(|v| v.parse::<f32>().ok()),
+                ) {
+                    return Ok(hsl_to_rgb(h, s / 100.0, l / 100.0));
+                }
+            }
+        }
+    }
+    if let Some((r, g, b)) = named_css_color(css) {
+        return Ok((r as f32 / 255.0, g as f32 / 255.0, b as f32 / 255.0));
+    }
+    anyhow::bail!(
+        "Unrecognized color '{}', expected a hex triplet, rgb()/rgba(), hsl()/hsla(), or a CSS color name",
+        css
+    )
+}
+
+/// Converts HSL (hue in degrees, saturation/lightness as 0.0-1.0 fractions)
+/// to the RGB triple PostScript expects, per the standard CSS3 conversion.
+fn hsl_to_rgb(h: f32, s: f32, l: f32) -> (f32, f32, f32) {
+    if s == 0.0 {
+        return (l, l, l);
+    }
+    let q = if l < 0.5 { l * (1.0 + s) } else { l + s - l * s };
+    let p = 2.0 * l - q;
+    // Hue in sixths of a turn, so the sector boundaries are exact in f32.
+    let h = h.rem_euclid(360.0) / 60.0;
+    let hue_to_rgb = |p: f32, q: f32, mut t: f32| {
+        if t < 0.0 {
+            t += 6.0;
+        }
+        if t > 6.0 {
+            t -= 6.0;
+        }
+        if t < 1.0 {
+            p + (q - p) * t
+        } else if t < 3.0 {
+            q
+        } else if t < 4.0 {
+            p + (q - p) * (4.0 - t)
+        } else {
+            p
+        }
+    };
+    (
+        hue_to_rgb(p, q, h + 2.0),
+        hue_to_rgb(p, q, h),
+        hue_to_rgb(p, q, h - 2.0),
+    )
+}
+
+/// Looks up a CSS3 extended named color, case-insensitively, by its 8-bit
+/// RGB triple.
+fn named_css_color(name: &str) -> Option<(u8, u8, u8)> {
+    let rgb = match name.to_lowercase().as_str() {
+        "aliceblue" => (240, 248, 255),
+        "antiquewhite" => (250, 235, 215),
+        "aqua" => (0, 255, 255),
+        "aquamarine" => (127, 255, 212),
+        "azure" => (240, 255, 255),
+        "beige" => (245, 245, 220),
+        "bisque" => (255, 228, 196),
+        "black" => (0, 0, 0),
+        "blanchedalmond" => (255, 235, 205),
+        "blue" => (0, 0, 255),
+        "blueviolet" => (138, 43, 226),
+        "brown" => (165, 42, 42),
+        "burlywood" => (222, 184, 135),
+        "cadetblue" => (95, 158, 160),
+        "chartreuse" => (127, 255, 0),
+        "chocolate" => (210, 105, 30),
+        "coral" => (255, 127, 80),
+        "cornflowerblue" => (100, 149, 237),
+        "cornsilk" => (255, 248, 220),
+        "crimson" => (220, 20, 60),
+        "cyan" => (0, 255, 255),
+        "darkblue" => (0, 0, 139),
+        "darkcyan" => (0, 139, 139),
+        "darkgoldenrod" => (184, 134, 11),
+        "darkgray" | "darkgrey" => (169, 169, 169),
+        "darkgreen" => (0, 100, 0),
+        "darkkhaki" => (189, 183, 107),
+        "darkmagenta" => (139, 0, 139),
+        "darkolivegreen" => (85, 107, 47),
+        "darkorange" => (255, 140, 0),
+        "darkorchid" => (153, 50, 204),
+        "darkred" => (139, 0, 0),
+        "darksalmon" => (233, 150, 122),
+        "darkseagreen" => (143, 188, 143),
+        "darkslateblue" => (72, 61, 139),
+        "darkslategray" | "darkslategrey" => (47, 79, 79),
+        "darkturquoise" => (0, 206, 209),
+        "darkviolet" => (148, 0, 211),
+        "deeppink" => (255, 20, 147),
+        "deepskyblue" => (0, 191, 255),
+        "dimgray" | "dimgrey" => (105, 105, 105),
+        "dodgerblue" => (30, 144, 255),
+        "firebrick" => (178, 34, 34),
+        "floralwhite" => (255, 250, 240),
+        "forestgreen" => (34, 139, 34),
+        "fuchsia" => (255, 0, 255),
+        "gainsboro" => (220, 220, 220),
+        "ghostwhite" => (248, 248, 255),
+        "gold" => (255, 215, 0),
+        "goldenrod" => (218, 165, 32),
+        "gray" | "grey" => (128, 128, 128),
+        "green" => (0, 128, 0),
+        "greenyellow" => (173, 255, 47),
+        "honeydew" => (240, 255, 240),
+        "hotpink" => (255, 105, 180),
+        "indianred" => (205, 92, 92),
+        "indigo" => (75, 0, 130),
+        "ivory" => (255, 255, 240),
+        "khaki" => (240, 230, 140),
+        "lavender" => (230, 230, 250),
+        "lavenderblush" => (255, 240, 245),
+        "lawngreen" => (124, 252, 0),
+        "lemonchiffon" => (255, 250, 205),
+        "lightblue" => (173, 216, 230),
+        "lightcoral" => (240, 128, 128),
+        "lightcyan" => (224, 255, 255),
+        "lightgoldenrodyellow" => (250, 250, 210),
+        "lightgray" | "lightgrey" => (211, 211, 211),
+        "lightgreen" => (144, 238, 144),
+        "lightpink" => (255, 182, 193),
+        "lightsalmon" => (255, 160, 122),
+        "lightseagreen" => (32, 178, 170),
+        "lightskyblue" => (135, 206, 250),
+        "lightslategray" | "lightslategrey" => (119, 136, 153),
+        "lightsteelblue" => (176, 196, 222),
+        "lightyellow" => (255, 255, 224),
+        "lime" => (0, 255, 0),
+        "limegreen" => (50, 205, 50),
+        "linen" => (250, 240, 230),
+        "magenta" => (255, 0, 255),
+        "maroon" => (128, 0, 0),
+        "mediumaquamarine" => (102, 205, 170),
+        "mediumblue" => (0, 0, 205),
+        "mediumorchid" => (186, 85, 211),
+        "mediumpurple" => (147, 112, 219),
+        "mediumseagreen" => (60, 179, 113),
+        "mediumslateblue" => (123, 104, 238),
+        "mediumspringgreen" => (0, 250, 154),
+        "mediumturquoise" => (72, 209, 204),
+        "mediumvioletred" => (199, 21, 133),
+        "midnightblue" => (25, 25, 112),
+        "mintcream" => (245, 255, 250),
+        "mistyrose" => (255, 228, 225),
+        "moccasin" => (255, 228, 181),
+        "navajowhite" => (255, 222, 173),
+        "navy" => (0, 0, 128),
+        "oldlace" => (253, 245, 230),
+        "olive" => (128, 128, 0),
+        "olivedrab" => (107, 142, 35),
+        "orange" => (255, 165, 0),
+        "orangered" => (255, 69, 0),
+        "orchid" => (218, 112, 214),
+        "palegoldenrod" => (238, 232, 170),
+        "palegreen" => (152, 251, 152),
+        "paleturquoise" => (175, 238, 238),
+        "palevioletred" => (219, 112, 147),
+        "papayawhip" => (255, 239, 213),
+        "peachpuff" => (255, 218, 185),
+        "peru" => (205, 133, 63),
+        "pink" => (255, 192, 203),
+        "plum" => (221, 160, 221),
+        "powderblue" => (176, 224, 230),
+        "purple" => (128, 0, 128),
+        "rebeccapurple" => (102, 51, 153),
+        "red" => (255, 0, 0),
+        "rosybrown" => (188, 143, 143),
+        "royalblue" => (65, 105, 225),
+        "saddlebrown" => (139, 69, 19),
+        "salmon" => (250, 128, 114),
+        "sandybrown" => (244, 164, 96),
+        "seagreen" => (46, 139, 87),
+        "seashell" => (255, 245, 238),
+        "sienna" => (160, 82, 45),
+        "silver" => (192, 192, 192),
+        "skyblue" => (135, 206, 235),
+        "slateblue" => (106, 90, 205),
+        "slategray" | "slategrey" => (112, 128, 144),
+        "snow" => (255, 250, 250),
+        "springgreen" => (0, 255, 127),
+        "steelblue" => (70, 130, 180),
+        "tan" => (210, 180, 140),
+        "teal" => (0, 128, 128),
+        "thistle" => (216, 191, 216),
+        "tomato" => (255, 99, 71),
+        "turquoise" => (64, 224, 208),
+        "violet" => (238, 130, 238),
+        "wheat" => (245, 222, 179),
+        "white" => (255, 255, 255),
+        "whitesmoke" => (245, 245, 245),
+        "yellow" => (255, 255, 0),
+        "yellowgreen" => (154, 205, 50),
+        _ => return None,
+    };
+    Some(rgb)
+}
+
+/// Writes `page` out as a single-page PostScript document to any `Write`
+/// (unlike [`crate::render_pdf`], which requires a `File`).
+pub fn render_ps(
+    writer: &mut dyn Write,
+    page: &Page,
+    auto_crop: bool,
+    layer_colors: &[LayerColor],
+    distance_threshold: f32,
+    template: Option<&str>,
+    debug_dump: bool,
+) -> Result<()> {
+    let content_bbox = BoundingBox::new().enclose_page(page);
+    let (min_x, min_y, max_x, max_y) = if auto_crop && content_bbox.max_x >= content_bbox.min_x {
+        (
+            content_bbox.min_x,
+            content_bbox.min_y,
+            content_bbox.max_x,
+            content_bbox.max_y,
+        )
+    } else {
+        (0.0, 0.0, REMARKABLE_WIDTH, REMARKABLE_HEIGHT)
+    };
+
+    writeln!(writer, "%!PS-Adobe-3.0 EPSF-3.0")?;
+    writeln!(
+        writer,
+        "%%BoundingBox: {} {} {} {}",
+        min_x.floor(),
+        min_y.floor(),
+        max_x.ceil(),
+        max_y.ceil()
+    )?;
+    if let Some(template) = template {
+        writeln!(writer, "%%Template: {}", template)?;
+    }
+    writeln!(writer, "%%EndComments")?;
+    writeln!(writer, "1 setlinecap\n1 setlinejoin")?;
+
+    for (layer_idx, layer) in page.layers.iter().enumerate() {
+        for line in layer.lines.iter() {
+            if line.points.is_empty() {
+                continue;
+            }
+            if debug_dump {
+                writeln!(
+                    writer,
+                    "% layer {} line, {} points, brush {:?}",
+                    layer_idx,
+                    line.points.len(),
+                    line.brush_type
+                )?;
+            }
+
+            let (r, g, b) = css_color_to_rgb(&line_to_css_color(line, layer_idx, layer_colors))?;
+            writeln!(writer, "{:.3} {:.3} {:.3} setrgbcolor", r, g, b)?;
+            writeln!(writer, "{:.3} setlinewidth", line.points[0].width)?;
+
+            writeln!(writer, "newpath")?;
+            let mut last_point: Option<&Point> = None;
+            let last_index = line.points.len() - 1;
+            for (i, point) in line.points.iter().enumerate() {
+                if let Some(last) = last_point {
+                    let dx = point.x - last.x;
+                    let dy = point.y - last.y;
+                    if i != last_index && dx * dx + dy * dy < distance_threshold * distance_threshold
+                    {
+                        continue;
+                    }
+                }
+                let op = if last_point.is_none() { "moveto" } else { "lineto" };
+                writeln!(writer, "{:.3} {:.3} {}", point.x, min_y + max_y - point.y, op)?;
+                last_point = Some(point);
+            }
+            writeln!(writer, "stroke")?;
+        }
+    }
+
+    writeln!(writer, "showpage")?;
+    writeln!(writer, "%%EOF")?;
+    Ok(())
+}
+
+/// Scales a `width`x`height` page (in reMarkable points) to the pixel
+/// dimensions it should be rasterized at for the given `dpi`, relative to the
+/// device's native [`REMARKABLE_DPI`]. Clamps to a minimum of 1 pixel per
+/// side so a page with zero-area content (e.g. an empty auto-cropped page)
+/// still produces a valid `ImageSurface`.
+fn png_pixel_size(width: f32, height: f32, dpi: f32) -> (i32, i32) {
+    let scale = dpi / REMARKABLE_DPI;
+    let pixel_width = (width * scale).ceil().max(1.0) as i32;
+    let pixel_height = (height * scale).ceil().max(1.0) as i32;
+    (pixel_width, pixel_height)
+}
+
+/// Rasterizes `page` to PNG and writes it to `writer`.
+///
+/// There's no pixel-pushing code here: we build the same SVG [`crate::render_svg`]
+/// would, parse it with `usvg`, and let `resvg` rasterize it onto a `cairo`
+/// `ImageSurface` at the requested `dpi` (226, the reMarkable's native density,
+/// by default). PNG is binary, but `cairo::ImageSurface::write_to_png` takes
+/// any `Write`, so this honors the same `File`/stdout output selection `main`
+/// already builds for the other formats.
+pub fn render_png(
+    writer: &mut dyn Write,
+    page: &Page,
+    auto_crop: bool,
+    layer_colors: &[LayerColor],
+    distance_threshold: f32,
+    template: Option<&str>,
+    dpi: f32,
+) -> Result<()> {
+    let mut svg_bytes = Vec::new();
+    // The declared SVG version only matters to consumers of the SVG file
+    // itself, not to usvg/resvg parsing it for rasterization, so this is
+    // always built as the default version.
+    crate::render_svg(
+        &mut svg_bytes,
+        page,
+        auto_crop,
+        layer_colors,
+        distance_threshold,
+        template,
+        false,
+        SvgVersion::default(),
+    )
+    .context("failed to build the SVG used as the PNG rasterization source")?;
+
+    let content_bbox = BoundingBox::new().enclose_page(page);
+    let (width, height) = if auto_crop && content_bbox.max_x >= content_bbox.min_x {
+        (
+            content_bbox.max_x - content_bbox.min_x,
+            content_bbox.max_y - content_bbox.min_y,
+        )
+    } else {
+        (REMARKABLE_WIDTH, REMARKABLE_HEIGHT)
+    };
+    let (pixel_width, pixel_height) = png_pixel_size(width, height, dpi);
+
+    let svg_tree = usvg::Tree::from_data(&svg_bytes, &usvg::Options::default().to_ref())
+        .context("failed to parse the generated SVG")?;
+
+    let surface = cairo::ImageSurface::create(cairo::Format::ARgb32, pixel_width, pixel_height)
+        .context("failed to allocate PNG surface")?;
+    let context = cairo::Context::new(&surface).context("failed to create cairo context")?;
+    let scale = dpi / REMARKABLE_DPI;
+    context.scale(scale as f64, scale as f64);
+    resvg::render_node(&svg_tree, usvg::FitTo::Original, &context)
+        .context("failed to rasterize SVG to PNG surface")?;
+
+    surface
+        .write_to_png(writer)
+        .context("failed to encode PNG")?;
+    Ok(())
+}
+
 #[test]
 fn test_segment_quads() {
     let line = Line::with_points(
@@ -111,3 +662,32 @@ fn test_segment_quads() {
         vec![-4.0, 3.0, -1.0, 7.0, 7.0, 1.0, 4.0, -3.0, 3.0, 9.0, 6.0, 9.0, 6.0, -1.0, 3.0, -1.0]
     );
 }
+
+#[test]
+fn test_css_color_to_rgb() {
+    assert_eq!(css_color_to_rgb("#ff0080").unwrap(), (1.0, 0.0, 128.0 / 255.0));
+    assert_eq!(css_color_to_rgb("rgb(0, 128, 255)").unwrap(), (0.0, 128.0 / 255.0, 1.0));
+    assert_eq!(css_color_to_rgb("rgba(0, 128, 255, 0.5)").unwrap(), (0.0, 128.0 / 255.0, 1.0));
+    assert_eq!(css_color_to_rgb("hsl(0, 100%, 50%)").unwrap(), (1.0, 0.0, 0.0));
+    assert_eq!(css_color_to_rgb("black").unwrap(), (0.0, 0.0, 0.0));
+    assert_eq!(css_color_to_rgb("white").unwrap(), (1.0, 1.0, 1.0));
+    assert_eq!(css_color_to_rgb("grey").unwrap(), css_color_to_rgb("gray").unwrap());
+    assert_eq!(
+        css_color_to_rgb("cornflowerblue").unwrap(),
+        (100.0 / 255.0, 149.0 / 255.0, 237.0 / 255.0)
+    );
+    assert!(css_color_to_rgb("notacolor").is_err());
+}
+
+#[test]
+fn test_png_pixel_size() {
+    assert_eq!(
+        png_pixel_size(REMARKABLE_WIDTH, REMARKABLE_HEIGHT, REMARKABLE_DPI),
+        (REMARKABLE_WIDTH.ceil() as i32, REMARKABLE_HEIGHT.ceil() as i32)
+    );
+    assert_eq!(
+        png_pixel_size(REMARKABLE_WIDTH, REMARKABLE_HEIGHT, REMARKABLE_DPI * 2.0),
+        ((REMARKABLE_WIDTH * 2.0).ceil() as i32, (REMARKABLE_HEIGHT * 2.0).ceil() as i32)
+    );
+    assert_eq!(png_pixel_size(0.0, 0.0, REMARKABLE_DPI), (1, 1));
+}